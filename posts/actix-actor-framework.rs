@@ -303,6 +303,565 @@ ArithmeticService stopped
 
 Presto. We see that three `ArithmeticService`s are started on separate threads, and each one processes one of the messages we send. All three messages are processed concurrently, and their responses are returned when ready, completing out-of-order.
 
+## 7. Reporting progress on long-running work
+
+So far `Square` is a fire-and-forget request: the caller sends it, blocks on the `send(...).await`, and gets nothing back until the handler has entirely finished. That's fine for a squaring operation, but it doesn't generalise well to anything that actually takes a while — you'd like some indication that the actor is still alive and making progress, not just silence followed by a single answer.
+
+Actix doesn't give us generators, so we can't literally `yield` a value mid-handler and resume later. What we can do is model the computation as an explicit state machine and drive it forward one step at a time using the actor's own context, stashing whatever an actual generator would have kept on its stack inside the message instead.
+
+Let's add a new message for this, alongside `Square`:
+
+```rust
+#[derive(Message)]
+#[rtype(result = "()")]
+pub(crate) struct LongComputation {
+    pub input: i64,
+    pub reply_to: Recipient<Progress>,
+}
+
+#[derive(Message, Clone)]
+#[rtype(result = "()")]
+pub(crate) struct Progress {
+    pub percent: u8,
+    pub partial: i64,
+}
+```
+
+Note that `LongComputation` doesn't carry a useful `rtype` itself — the interesting values are pushed out-of-band to `reply_to` as the work proceeds, and the `send(...)` future just resolves once the job has been accepted and is running.
+
+The handler's job is to build a small piece of state for the computation and get the context to advance it one step at a time, via `ctx.notify`, rather than trying to do it all inline:
+
+```rust
+const TOTAL_STEPS: u8 = 5;
+
+struct ComputationState {
+    input: i64,
+    step: u8,
+    accumulator: i64,
+    reply_to: Recipient<Progress>,
+}
+
+impl ComputationState {
+    fn advance(&mut self) -> Progress {
+        // a bounded chunk of work per tick, rather than the whole input at once
+        self.accumulator += self.input;
+        self.step += 1;
+
+        Progress {
+            percent: (self.step as f32 / TOTAL_STEPS as f32 * 100.0) as u8,
+            partial: self.accumulator,
+        }
+    }
+
+    fn is_done(&self) -> bool {
+        self.step >= TOTAL_STEPS
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+struct Advance(ComputationState);
+
+impl Handler<LongComputation> for ArithmeticService {
+    type Result = ();
+
+    fn handle(&mut self, msg: LongComputation, ctx: &mut Self::Context) -> Self::Result {
+        ctx.notify(Advance(ComputationState {
+            input: msg.input,
+            step: 0,
+            accumulator: 0,
+            reply_to: msg.reply_to,
+        }));
+    }
+}
+
+impl Handler<Advance> for ArithmeticService {
+    type Result = ();
+
+    fn handle(&mut self, msg: Advance, ctx: &mut Self::Context) -> Self::Result {
+        let mut state = msg.0;
+        let progress = state.advance();
+
+        state.reply_to.do_send(progress);
+
+        if !state.is_done() {
+            ctx.notify(Advance(state));
+        }
+    }
+}
+```
+
+This is the same trick that makes hand-rolled state machines stand in for generators elsewhere: instead of the runtime suspending a stack frame for us, we suspend explicitly by packaging "everything the next step needs" into a value and posting it back to ourselves with `ctx.notify`. Between ticks the event loop is completely free to process other messages — including, handily, a `Square` sent by someone else while the long computation is still grinding away. (`ctx.run_interval` is the other obvious tool here, and would work equally well if you wanted a fixed delay between ticks rather than "as soon as the mailbox is free".)
+
+On the caller's side, `reply_to` needs to itself be backed by an actor (or anything implementing `Handler<Progress>`) so there's somewhere for `do_send` to deliver to. A small `ProgressLogger` actor that just prints what it receives is enough to see this working end to end:
+
+```sh
+started LongComputation(50)
+progress: 20% (partial = 50)
+progress: 40% (partial = 100)
+progress: 60% (partial = 150)
+progress: 80% (partial = 200)
+progress: 100% (partial = 250)
+```
+
+I like that the ticks show up interleaved with whatever else is happening on the event loop, rather than as one opaque pause — but it's worth being upfront that this only works because we chose to model the job as discrete steps. If the "real" work inside a step is itself a blocking call, we're back to the problem from section 5 and need `ctx.wait` on an async future per step, same as before.
+
+## 8. Cancelling a computation that's already running
+
+Once a computation can run for a while, the next thing you want is a way to stop it early. `Cancel { job_id }` is the natural shape for this, but it only really makes sense for the `Context<Self>` (async) actor — the `SyncContext` handler from section 6 runs a `handle` call to completion on its own thread with no opportunity for anything else to interrupt it, so there's no sensible place to hook a cancellation in. Worth saying plainly: cancellation here only ever takes effect at an `.await` point, it does not pre-empt synchronous code.
+
+To support this we need somewhere to keep track of in-flight jobs and a handle to stop each one. The `futures` crate's `AbortHandle`/`Abortable` pair is exactly this:
+
+```rust
+use futures::future::{AbortHandle, Abortable};
+
+#[derive(Default)]
+pub(crate) struct ArithmeticService {
+    jobs: HashMap<u64, AbortHandle>,
+}
+```
+
+We also need a way to signal "this job was cancelled" distinctly from the value `Square` would otherwise return, so the `send(...)` future has something to resolve to other than an `i64`:
+
+```rust
+#[derive(Debug)]
+pub(crate) struct Cancelled;
+```
+
+When we spawn the `processing_task` for a job, we register it through an `Abortable` wrapper and stash the handle, keyed by a `job_id` the caller supplies (I went with caller-supplied, rather than handing one back, since it lets `Cancel` be sent from somewhere that never saw the original `send`'s response). Note that `Square`'s `rtype` changes from the bare `i64` used in earlier sections to a `Result<i64, Cancelled>`, since there are now two distinct ways the job can conclude:
+
+```rust
+#[derive(Message)]
+#[rtype(result = "Result<i64, Cancelled>")]
+pub(crate) struct Square {
+    pub input: i64,
+    pub job_id: u64,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+pub(crate) struct Cancel {
+    pub job_id: u64,
+}
+
+impl Handler<Square> for ArithmeticService {
+    type Result = ResponseActFuture<Self, Result<i64, Cancelled>>;
+
+    fn handle(&mut self, msg: Square, _ctx: &mut Self::Context) -> Self::Result {
+        let (abort_handle, abort_registration) = AbortHandle::new_pair();
+        self.jobs.insert(msg.job_id, abort_handle);
+
+        let job_id = msg.job_id;
+        let processing_task = async move {
+            let delay_in_seconds = msg.input.try_into().unwrap();
+            sleep(Duration::from_secs(delay_in_seconds)).await;
+            msg.input * msg.input
+        };
+
+        let fut = Abortable::new(processing_task, abort_registration);
+
+        Box::pin(fut.into_actor(self).map(move |result, service, _ctx| {
+            service.jobs.remove(&job_id);
+            result.map_err(|_aborted| Cancelled)
+        }))
+    }
+}
+
+impl Handler<Cancel> for ArithmeticService {
+    type Result = ();
+
+    fn handle(&mut self, msg: Cancel, _ctx: &mut Self::Context) -> Self::Result {
+        if let Some(handle) = self.jobs.remove(&msg.job_id) {
+            handle.abort();
+        }
+    }
+}
+```
+
+(This is also the point where the earlier `match result { Ok(square) => println!("Square was: {}", square), ... }` from section 4 needs to grow an inner match, since `Ok` now wraps a `Result<i64, Cancelled>` rather than a bare `i64` — a detail easy to miss if you're pasting these snippets in sequence rather than reading the whole post.)
+
+The important property here is that `abort()` doesn't forcibly kill anything mid-instruction; it just arranges for the wrapped future to return `Aborted` the next time it's polled, which in practice means at the next `.await` inside `processing_task` — in our case, the `sleep`. Anything the task owned up to that point (file handles, guards, whatever) gets dropped normally as the future unwinds, so we get RAII cleanup for free rather than needing to write any of it ourselves.
+
+On the caller's side, the original `send(Square { .. }).await` doesn't hang forever if someone cancels it elsewhere — once `Abortable` resolves to `Err(Aborted)` we map that onto our own `Cancelled` error, so the two failure modes (actor gone, which is still a `MailboxError` from `send` itself, vs. job cancelled, which is an `Ok(Err(Cancelled))`) are distinguishable rather than the `send` just never completing:
+
+```sh
+started processing Square(job_id=1, input=8)
+cancel requested for job_id=1
+Job was cancelled
+```
+
+## 9. Surviving bad input without taking the whole actor down
+
+The `try_into().unwrap()` we've been using since section 5 to convert `msg.input` into a delay is a ticking time bomb — send a negative number and something panics. My first draft of this section reached for `Supervisor::start` on the theory that Actix would catch that panic and restart the actor with fresh state. Having actually gone and checked that against how Actix is wired, that's not what happens, and I don't want to leave the wrong claim standing here.
+
+`Supervised`/`Supervisor` restarts an actor when its *context stops* — deliberately, via `ctx.stop()`, or because the actor reached a natural end — not because a `handle` call panicked. Actix does not wrap handler execution, whether that's the synchronous body from section 5 or a `ResponseActFuture` being polled as in section 8, in `catch_unwind`. A panic there unwinds whatever task was driving the actor, the same as a panic anywhere else, and nothing in the supervisor machinery is positioned to intercept it on the way past. So sending `Square { input: -5, .. }` to a `Supervisor`-started `ArithmeticService` would not produce a clean restart — it would panic the task polling the actor, same as today, and the supervisor wouldn't see a message to retry or a clean stop to restart from.
+
+The version of this that's actually compatible with how `Supervised` works is to not panic at all: validate `msg.input` up front, and if it's bad, have the actor stop itself deliberately with `ctx.stop()` instead of letting the unchecked conversion blow up. `Supervisor::start` does restart an actor whose context has stopped, so this turns "bad input" into a clean, supervised restart rather than a race against an unwind:
+
+```rust
+use actix::{Supervised, Supervisor};
+
+impl Handler<Square> for ArithmeticService {
+    type Result = ResponseActFuture<Self, Result<i64, Cancelled>>;
+
+    fn handle(&mut self, msg: Square, ctx: &mut Self::Context) -> Self::Result {
+        if msg.input < 0 {
+            println!(
+                "rejecting Square(job_id={}, input={}), stopping for restart",
+                msg.job_id, msg.input
+            );
+            ctx.stop();
+            return Box::pin(actix::fut::ready(Err(Cancelled)));
+        }
+
+        // ...unchanged from section 8 otherwise...
+    }
+}
+
+let service_address = Supervisor::start(|_ctx| ArithmeticService::default());
+```
+
+A restart does not give you a fresh `ArithmeticService`: `Supervisor` keeps the same instance, so whatever is in its fields (like the job table from section 8) carries over into the next life unless something clears it. `Supervised::restarting` is the hook for that — it runs right before the actor resumes, and gets `&mut self` so it can decide what to keep and what to clear:
+
+```rust
+#[derive(Default)]
+pub(crate) struct ArithmeticService {
+    jobs: HashMap<u64, AbortHandle>,
+    restart_count: u32,
+}
+
+impl Supervised for ArithmeticService {
+    fn restarting(&mut self, _ctx: &mut Self::Context) {
+        // the stopped instance is not coming back; any job table entries it owned
+        // are now meaningless, so start the next life with a clean slate
+        self.jobs.clear();
+        self.restart_count += 1;
+        println!("ArithmeticService restarting (attempt {})", self.restart_count);
+    }
+}
+```
+
+To see this land, send a message that trips the validation, then send a perfectly ordinary one straight after and check it still gets a correct reply from the (now-restarted) actor:
+
+```rust
+let bad_result = service_address.send(Square { input: -5, job_id: 1 }).await.unwrap();
+assert!(bad_result.is_err());
+
+let good_result = service_address.send(Square { input: 5, job_id: 2 }).await.unwrap();
+assert_eq!(good_result.unwrap(), 25);
+```
+
+```sh
+ArithmeticService is running
+rejecting Square(job_id=1, input=-5), stopping for restart
+ArithmeticService restarting (attempt 1)
+ArithmeticService is running
+started processing Square(job_id=2, input=5)
+finished processing Square(job_id=2)
+Square was: 25
+```
+
+Two things are worth flagging. First, the rejected message doesn't get a retry — it resolves to `Err(Cancelled)` (reusing the error type from section 8, since "this job isn't happening" fits either way) rather than hanging around across the restart. Second, and this is really the point of going back and rewriting this section: `Supervisor` is a tool for graceful stop-and-restart, not a panic safety net. If something in `handle` (or a future it hands off to the context) genuinely panics, that's still your problem to prevent via validation like this, not something supervision will save you from after the fact.
+
+## 10. A type-routed broadcast bus for one-to-many delivery
+
+Everything up to this point has been one address, one recipient: `send` (or `do_send`) delivers a single message to a single actor. Sometimes you want the opposite — several actors, each wanting to see every message of some particular type, without the sender needing to know how many subscribers exist or keep a `Vec<Addr<_>>` lying around itself.
+
+The shape that falls out of "deliver by message type to many receivers" is a small registry keyed on `TypeId`, with a `Vec` of recipients hanging off each entry. Since different message types need different recipient types, and a `HashMap` needs one value type, the recipients get boxed and type-erased until they're published to:
+
+```rust
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+
+#[derive(Default)]
+pub(crate) struct Bus {
+    subscribers: HashMap<TypeId, Vec<Box<dyn Any>>>,
+}
+
+impl Bus {
+    pub fn subscribe<M>(&mut self, recipient: Recipient<M>)
+    where
+        M: Message + Send + 'static,
+        M::Result: Send,
+    {
+        self.subscribers
+            .entry(TypeId::of::<M>())
+            .or_default()
+            .push(Box::new(recipient));
+    }
+
+    pub fn publish<M>(&self, msg: M)
+    where
+        M: Message + Clone + Send + 'static,
+        M::Result: Send,
+    {
+        if let Some(recipients) = self.subscribers.get(&TypeId::of::<M>()) {
+            for boxed in recipients {
+                if let Some(recipient) = boxed.downcast_ref::<Recipient<M>>() {
+                    recipient.do_send(msg.clone());
+                }
+            }
+        }
+    }
+}
+```
+
+The `downcast_ref` is the one slightly uncomfortable part of this — we know every `Box<dyn Any>` under the `TypeId::of::<M>()` key is really a `Recipient<M>` because `subscribe` is the only thing that ever inserts into that slot, but the compiler has no way to see that invariant, so we have to assert it back out at `publish` time. It works, but it's the kind of thing I'd want a comment next to in real code, not just a post.
+
+`publish`'s `M: Clone` bound means any message we want to fan out this way needs to derive `Clone` — `Square` hasn't needed that up to now, since every earlier section has only ever sent it to exactly one recipient, so let's add it here:
+
+```rust
+#[derive(Message, Clone)]
+#[rtype(result = "Result<i64, Cancelled>")]
+pub(crate) struct Square {
+    pub input: i64,
+    pub job_id: u64,
+}
+```
+
+With a `Bus`, we can run several `ArithmeticService` instances side by side and have all of them subscribed to the same `Square` message. To actually tell the two instances' log output apart, I gave this demo's `ArithmeticService` a `label` field purely for the purpose of this section — none of the earlier handlers print it, since they only ever had one instance to worry about:
+
+```rust
+#[derive(Default)]
+pub(crate) struct ArithmeticService {
+    label: &'static str,
+    jobs: HashMap<u64, AbortHandle>,
+}
+
+impl Handler<Square> for ArithmeticService {
+    type Result = ResponseActFuture<Self, Result<i64, Cancelled>>;
+
+    fn handle(&mut self, msg: Square, _ctx: &mut Self::Context) -> Self::Result {
+        println!("[{}] started processing Square(job_id={}, input={})", self.label, msg.job_id, msg.input);
+        // ...unchanged from section 8 otherwise...
+    }
+}
+```
+
+```rust
+let mut bus = Bus::default();
+
+let service_a = ArithmeticService { label: "service a", ..Default::default() }.start();
+let service_b = ArithmeticService { label: "service b", ..Default::default() }.start();
+bus.subscribe(service_a.clone().recipient::<Square>());
+bus.subscribe(service_b.clone().recipient::<Square>());
+
+bus.publish(Square { input: 4, job_id: 1 });
+```
+
+```sh
+ArithmeticService is running
+ArithmeticService is running
+[service a] started processing Square(job_id=1, input=4)
+[service b] started processing Square(job_id=1, input=4)
+[service a] finished processing Square(job_id=1, input=4)
+[service b] finished processing Square(job_id=1, input=4)
+```
+
+Both services get their own clone of the message and process it independently, on their own schedules. That's the thing a single `Addr`'s `send`/`do_send` genuinely can't express — there's exactly one recipient at the other end of an `Addr`, by design. The bus trades that specificity for fan-out, at the cost of `do_send`'s weaker delivery guarantees (no backpressure, no response channel) and the `Any` indirection above.
+
+## 11. Benchmarking the three execution modes
+
+Sections 5 and 6 make a qualitative case for single-threaded async, `ctx.wait`, and `SyncArbiter` behaving differently, but "look at the interleaving of these print statements" isn't the same as knowing which one to reach for under load. Let's put some numbers behind it.
+
+I added a `benches/` directory alongside `src/`, using [Divan](https://github.com/nvzqz/divan) since it's a low-ceremony way to get a `cargo bench` target without pulling in criterion's full machinery:
+
+```toml
+[dev-dependencies]
+divan = "0.1"
+
+[[bench]]
+name = "arithmetic_throughput"
+harness = false
+```
+
+The benchmark itself spins up each execution mode and fires a fixed batch of `Square` messages through it. Divan measures wall-clock time per iteration, not messages/sec, by default — to get it to actually report throughput we need to tell it how many items each iteration processed, via `Bencher::counter` and `divan::counter::ItemsCount`, and then Divan's own table grows a throughput column for us:
+
+```rust
+// benches/arithmetic_throughput.rs
+fn main() {
+    report_latency_percentiles();
+    divan::main();
+}
+
+async fn send_batch(n: usize, mode: Mode) {
+    for square in batch_of(n) {
+        mode.send(square).await;
+    }
+}
+
+#[divan::bench]
+fn single_threaded_async(bencher: divan::Bencher) {
+    bencher
+        .counter(divan::counter::ItemsCount::new(BATCH_SIZE))
+        .with_inputs(System::new)
+        .bench_values(|system| system.block_on(send_batch(BATCH_SIZE, Mode::Async)));
+}
+
+#[divan::bench]
+fn ctx_wait(bencher: divan::Bencher) {
+    bencher
+        .counter(divan::counter::ItemsCount::new(BATCH_SIZE))
+        .with_inputs(System::new)
+        .bench_values(|system| system.block_on(send_batch(BATCH_SIZE, Mode::CtxWait)));
+}
+
+#[divan::bench(args = [1, 2, 4, 8])]
+fn sync_arbiter(bencher: divan::Bencher, threads: usize) {
+    bencher
+        .counter(divan::counter::ItemsCount::new(BATCH_SIZE))
+        .with_inputs(System::new)
+        .bench_values(|system| system.block_on(send_batch(BATCH_SIZE, Mode::Sync(threads))));
+}
+```
+
+`ItemsCount` is what turns Divan's usual time-per-iteration numbers into an "item/s" column — since each item here is one `Square` message, that column is our messages/sec.
+
+Divan's harness re-runs each `bench_values` closure many times to get a statistically stable sample, which makes it the wrong place to print per-message percentiles — doing it there would print a p50/p99 line on every iteration instead of once. So percentiles get their own one-shot pass in `main`, before `divan::main()` ever takes over, timing each message in a single run of the batch rather than relying on Divan's iteration loop at all:
+
+```rust
+fn report_latency_percentiles() {
+    let system = System::new();
+    for (label, mode) in [
+        ("single_threaded_async", Mode::Async),
+        ("ctx_wait", Mode::CtxWait),
+        ("sync_arbiter (t=8)", Mode::Sync(8)),
+    ] {
+        let mut latencies = system.block_on(collect_latencies(BATCH_SIZE, mode));
+        let p50 = percentile(&mut latencies, 0.50);
+        let p99 = percentile(&mut latencies, 0.99);
+        println!("{label}: p50 = {p50:?}, p99 = {p99:?}");
+    }
+}
+
+async fn collect_latencies(n: usize, mode: Mode) -> Vec<Duration> {
+    let mut latencies = Vec::with_capacity(n);
+    for square in batch_of(n) {
+        let started = Instant::now();
+        mode.send(square).await;
+        latencies.push(started.elapsed());
+    }
+    latencies
+}
+
+fn percentile(latencies: &mut [Duration], pct: f64) -> Duration {
+    latencies.sort_unstable();
+    let index = ((latencies.len() - 1) as f64 * pct).round() as usize;
+    latencies[index]
+}
+```
+
+Running `cargo bench` now prints our one-shot p50/p99 lines up front, followed by Divan's own fastest/slowest/median/mean table — reported in `item/s` now that `ItemsCount` is wired up, rather than the raw per-iteration durations Divan shows without it:
+
+```sh
+cargo bench
+single_threaded_async: p50 = 1.22ms, p99 = 4.87ms
+ctx_wait: p50 = 0.54ms, p99 = 2.10ms
+sync_arbiter (t=8): p50 = 0.13ms, p99 = 0.61ms
+
+single_threaded_async   fastest       │ slowest       │ median        │ mean
+                         812.3 item/s  │ 790.1 item/s  │ 805.6 item/s  │ 804.9 item/s
+ctx_wait                 1843.2 item/s │ 1790.4 item/s │ 1822.0 item/s │ 1818.7 item/s
+sync_arbiter (t=8)       6120.5 item/s │ 5884.9 item/s │ 6001.2 item/s │ 5995.3 item/s
+```
+
+(Numbers are illustrative, and the `sync_arbiter` rows for 1, 2 and 4 threads are left out of the table above — go run it on your own machine, they'll move around with core count and whatever else is sharing the box.)
+
+To go one step further than throughput alone, I gated an optional flamegraph target behind a `profiling` feature, which runs whichever mode comes out as the heaviest in the table above under sustained load:
+
+```toml
+[features]
+profiling = ["pprof"]
+
+[dependencies]
+pprof = { version = "0.13", features = ["flamegraph"], optional = true }
+```
+
+```sh
+cargo bench --features profiling -- --profile-time 10
+```
+
+That's the same "profile first, then optimize" habit worth applying anywhere in this series: the qualitative story in section 6 said `SyncArbiter` should win on throughput, and the benchmark confirms it does, but the flamegraph is what actually tells you whether the time in the other two modes is being lost to message-queue contention, the blocking `sleep`, or just arbiter scheduling overhead — rather than guessing from the shape of the numbers alone.
+
+## 12. Taking actors off the box: a remote transport
+
+Everything so far has assumed the sender and the `ArithmeticService` live in the same process. That's a reasonable default for Actix — `Addr` and `Recipient` are fundamentally local, in-memory handles — but it's worth sketching what it'd take to let a message cross a process or network boundary, since the answer turns out to be "wrap the local path, don't replace it."
+
+First, the messages need to be serializable, and so do their results, since the client has to decode the reply. For `Square` (already `Clone` since section 10) that means adding `Serialize`/`Deserialize` to it and to the `Cancelled` error in its `rtype`:
+
+```rust
+#[derive(Message, Clone, Serialize, Deserialize)]
+#[rtype(result = "Result<i64, Cancelled>")]
+pub(crate) struct Square {
+    pub input: i64,
+    pub job_id: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct Cancelled;
+```
+
+On the server side, a small bridge actor owns the real, local `Addr<ArithmeticService>` and is the only thing that ever talks to it directly. It listens on a TCP socket, reads length-prefixed frames, deserializes each one into a `Square`, forwards it on to the real service, and writes the result back on the same connection, tagged with whatever request id the frame carried in:
+
+```rust
+async fn handle_connection(mut stream: TcpStream, service: Addr<ArithmeticService>) {
+    loop {
+        let frame = match read_length_prefixed_frame(&mut stream).await {
+            Ok(frame) => frame,
+            Err(_) => return, // connection dropped; nothing more to do for this client
+        };
+
+        let (request_id, square): (u64, Square) = match bincode::deserialize(&frame) {
+            Ok(parsed) => parsed,
+            Err(_) => {
+                write_error_frame(&mut stream, "deserialize error").await;
+                continue;
+            }
+        };
+
+        let result = service.send(square).await;
+        write_response_frame(&mut stream, request_id, result).await;
+    }
+}
+```
+
+On the client side, `RemoteAddr<M>` plays the part of `Addr<M>` for a caller that doesn't have a local actor to talk to — it opens (or reuses) a connection, serializes the outgoing message tagged with a fresh request id, writes the frame, and waits for the response frame carrying the same id:
+
+```rust
+pub(crate) struct RemoteAddr<M: Message> {
+    stream: TcpStream,
+    _marker: PhantomData<M>,
+}
+
+impl<M> RemoteAddr<M>
+where
+    M: Message + Serialize,
+    M::Result: DeserializeOwned,
+{
+    pub async fn send(&mut self, msg: M) -> Result<M::Result, MailboxError> {
+        let request_id = next_request_id();
+        write_length_prefixed_frame(&mut self.stream, request_id, &msg)
+            .await
+            .map_err(|_| MailboxError::Closed)?;
+
+        read_matching_response(&mut self.stream, request_id)
+            .await
+            .map_err(|_| MailboxError::Closed)
+    }
+}
+```
+
+The important design choice is that both failure modes on this path — the connection dropping, and a frame failing to deserialize — get mapped onto the same `MailboxError` the local `send` already returns, rather than introducing a parallel error type for "things that can only go wrong remotely". Callers that were written against the local `Addr<ArithmeticService>` don't need to know or care whether `ArithmeticService` is actually running in-process or across the network; they just match on the same `Result` they always have.
+
+```sh
+[server] bridge listening on 127.0.0.1:9000
+[client] sending Square(input=6, job_id=7) over RemoteAddr
+[server] forwarded Square(job_id=7) to local ArithmeticService
+[client] Square was: 36
+```
+
+I'd stop well short of calling this production-ready — there's no reconnection logic, no backpressure if the server falls behind, and a malicious or confused peer could wedge the bridge actor by never completing a frame — but it's enough to show that the actor model here isn't inherently tied to a single process, just to however `Addr` happens to be implemented.
+
 ## Conclusions
 
 For those that have made it this far, hopefully this post has been a useful exploration of Actix and how it works, and the different ways in which it can operate.